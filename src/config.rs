@@ -1,15 +1,42 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub commit_prefixes: Vec<String>,
     pub branch_prefixes: Vec<String>,
     pub story_prefix: Option<String>,
     pub auto_push: Option<bool>,
     pub default_commit_prefix: Option<String>,
+    pub project_roots: Option<Vec<String>>,
+    /// Named profiles (e.g. `[profiles.work]`) that can be layered over the top-level
+    /// fields above; see `active_profile` and `Config::load`
+    pub profiles: Option<HashMap<String, ConfigOverlay>>,
+    /// Name of the profile to apply by default, overridable with `gitcc --profile <name>`
+    pub active_profile: Option<String>,
+    /// Path to a file whose contents pre-populate the commit message body, with `~`/
+    /// `$VAR` expansion and `{prefix}`/`{story}` placeholder substitution; see
+    /// `git::render_commit_template`
+    pub commit_template: Option<String>,
+}
+
+/// A partial config, deserialized either from a project-local `.gitcc.toml` or from a
+/// `[profiles.*]` table entry. Every field is optional so that only the keys being
+/// overridden need to be specified; see `Config::merge_overlay` for how each is applied.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct ConfigOverlay {
+    commit_prefixes: Option<Vec<String>>,
+    commit_prefixes_extend: Option<Vec<String>>,
+    branch_prefixes: Option<Vec<String>>,
+    branch_prefixes_extend: Option<Vec<String>>,
+    story_prefix: Option<String>,
+    auto_push: Option<bool>,
+    default_commit_prefix: Option<String>,
+    project_roots: Option<Vec<String>>,
+    commit_template: Option<String>,
 }
 
 impl Default for Config {
@@ -41,19 +68,56 @@ impl Default for Config {
             story_prefix: None,
             auto_push: Some(true),
             default_commit_prefix: None,
+            project_roots: None,
+            profiles: None,
+            active_profile: None,
+            commit_template: None,
         }
     }
 }
 
 impl Config {
-    /// Loads config from ~/.gitcc/config.toml or creates default if not found
-    pub fn load() -> Result<Self> {
+    /// Loads config by resolving a short chain of sources, closest wins: a repo-local
+    /// `.gitcc.toml` (searched by walking up from the current directory to `repo_root`,
+    /// when given) is merged field-by-field over the active named profile (`profile`,
+    /// falling back to the config's own `active_profile`), which is itself merged over
+    /// the user-level config discovered by `config_path`. The user-level config is
+    /// created with defaults if missing.
+    ///
+    /// Between the profile step and the `.gitcc.toml` overlay, a legacy repo-root
+    /// `.gitcommitcreator.toml` (the original project-config file, predating
+    /// `.gitcc.toml`) is still honored if present, for any team that already committed
+    /// one: see `load_legacy_repo_config`.
+    pub fn load(repo_root: Option<&Path>, profile: Option<&str>) -> Result<Self> {
+        let mut config = Self::load_global()?;
+
+        if let Some(profile_name) = profile.or(config.active_profile.as_deref()) {
+            let overlay = config
+                .profiles
+                .as_ref()
+                .and_then(|profiles| profiles.get(profile_name))
+                .cloned()
+                .with_context(|| format!("Unknown profile '{profile_name}'"))?;
+            config = Self::merge_overlay(config, overlay);
+        }
+
+        if let Some(legacy) = Self::load_legacy_repo_config(repo_root)? {
+            config = legacy;
+        }
+
+        match Self::find_project_overlay(repo_root)? {
+            Some(overlay) => Ok(Self::merge_overlay(config, overlay)),
+            None => Ok(config),
+        }
+    }
+
+    /// Loads the user-level config, creating it from defaults if it doesn't exist yet
+    fn load_global() -> Result<Self> {
         let config_path = Self::config_path()?;
 
         if config_path.exists() {
             let content = fs::read_to_string(&config_path)?;
-            let config: Config = toml::from_str(&content)?;
-            Ok(config)
+            Ok(toml::from_str(&content)?)
         } else {
             let config = Config::default();
             config.save()?;
@@ -61,7 +125,94 @@ impl Config {
         }
     }
 
-    /// Saves config to ~/.gitcc/config.toml
+    /// Loads the legacy repo-root `.gitcommitcreator.toml` config file (shipped before
+    /// `.gitcc.toml` project overlays existed), if one is present at `repo_root` exactly.
+    /// Unlike the newer overlay mechanism, this file replaces the config wholesale
+    /// rather than merging field-by-field, preserving the original full-file behavior
+    /// for any team that already committed one.
+    fn load_legacy_repo_config(repo_root: Option<&Path>) -> Result<Option<Self>> {
+        let Some(repo_root) = repo_root else {
+            return Ok(None);
+        };
+        let legacy_path = repo_root.join(".gitcommitcreator.toml");
+        if !legacy_path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&legacy_path)?;
+        Ok(Some(toml::from_str(&content)?))
+    }
+
+    /// Walks up from the current directory to `repo_root` (inclusive), returning the
+    /// closest `.gitcc.toml` found, if any
+    fn find_project_overlay(repo_root: Option<&Path>) -> Result<Option<ConfigOverlay>> {
+        let Some(repo_root) = repo_root else {
+            return Ok(None);
+        };
+        let repo_root = repo_root
+            .canonicalize()
+            .unwrap_or_else(|_| repo_root.to_path_buf());
+
+        let mut dir = std::env::current_dir()?;
+        dir = dir.canonicalize().unwrap_or(dir);
+
+        loop {
+            let candidate = dir.join(".gitcc.toml");
+            if candidate.exists() {
+                let content = fs::read_to_string(&candidate)?;
+                return Ok(Some(toml::from_str(&content)?));
+            }
+
+            if dir == repo_root {
+                return Ok(None);
+            }
+
+            match dir.parent() {
+                Some(parent) => dir = parent.to_path_buf(),
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Applies a project-local overlay on top of a base config. Scalars use `Option`
+    /// semantics (present overwrites, absent leaves the base untouched); the prefix
+    /// lists support full replacement via `commit_prefixes`/`branch_prefixes` and
+    /// additive merging via `commit_prefixes_extend`/`branch_prefixes_extend`
+    fn merge_overlay(mut base: Self, overlay: ConfigOverlay) -> Self {
+        if let Some(commit_prefixes) = overlay.commit_prefixes {
+            base.commit_prefixes = commit_prefixes;
+        }
+        if let Some(extend) = overlay.commit_prefixes_extend {
+            base.commit_prefixes.extend(extend);
+        }
+
+        if let Some(branch_prefixes) = overlay.branch_prefixes {
+            base.branch_prefixes = branch_prefixes;
+        }
+        if let Some(extend) = overlay.branch_prefixes_extend {
+            base.branch_prefixes.extend(extend);
+        }
+
+        if overlay.story_prefix.is_some() {
+            base.story_prefix = overlay.story_prefix;
+        }
+        if overlay.auto_push.is_some() {
+            base.auto_push = overlay.auto_push;
+        }
+        if overlay.default_commit_prefix.is_some() {
+            base.default_commit_prefix = overlay.default_commit_prefix;
+        }
+        if overlay.project_roots.is_some() {
+            base.project_roots = overlay.project_roots;
+        }
+        if overlay.commit_template.is_some() {
+            base.commit_template = overlay.commit_template;
+        }
+
+        base
+    }
+
+    /// Saves config to the user-level config path
     pub fn save(&self) -> Result<()> {
         let config_path = Self::config_path()?;
 
@@ -74,10 +225,104 @@ impl Config {
         Ok(())
     }
 
-    /// Returns path to config file
-    fn config_path() -> Result<PathBuf> {
+    /// Ensures the user-level config file exists, writing out `Config::default()` first
+    /// if it's missing, and returns its path. Lets `gitcc config get/set/edit` operate
+    /// on a brand-new machine without ever hitting "file not found".
+    pub fn ensure_exists() -> Result<PathBuf> {
+        let path = Self::config_path()?;
+        if !path.exists() {
+            Config::default().save()?;
+        }
+        Ok(path)
+    }
+
+    /// Returns the path to the user-level config file, honoring (in order) the
+    /// `GITCC_CONFIG` env var (an absolute path to a TOML file), `$XDG_CONFIG_HOME`,
+    /// and finally falling back to `~/.config/gitcommitcreator/config.toml`
+    pub fn config_path() -> Result<PathBuf> {
+        if let Ok(gitcc_config) = std::env::var("GITCC_CONFIG") {
+            if !gitcc_config.is_empty() {
+                return Ok(PathBuf::from(gitcc_config));
+            }
+        }
+
+        if let Ok(xdg_config_home) = std::env::var("XDG_CONFIG_HOME") {
+            if !xdg_config_home.is_empty() {
+                return Ok(PathBuf::from(xdg_config_home)
+                    .join("gitcommitcreator")
+                    .join("config.toml"));
+            }
+        }
+
         let home =
             dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
-        Ok(home.join(".gitcc").join("config.toml"))
+        Ok(home
+            .join(".config")
+            .join("gitcommitcreator")
+            .join("config.toml"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `Config::load` touches both `$GITCC_CONFIG` and the process cwd (via
+    /// `find_project_overlay`'s walk-up), so this test runs single-threaded within
+    /// itself by restoring both before returning; it must not be split across
+    /// `#[test]` fns that could interleave with a parallel test runner.
+    #[test]
+    fn load_layers_legacy_repo_config_and_gitcc_overlay_over_global() {
+        let original_dir = std::env::current_dir().unwrap();
+        let original_gitcc_config = std::env::var("GITCC_CONFIG").ok();
+
+        let tmp = std::env::temp_dir().join(format!(
+            "gitcc-config-test-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&tmp).unwrap();
+
+        let global_path = tmp.join("global-config.toml");
+        fs::write(
+            &global_path,
+            toml::to_string_pretty(&Config::default()).unwrap(),
+        )
+        .unwrap();
+        std::env::set_var("GITCC_CONFIG", &global_path);
+
+        let repo_root = tmp.join("repo");
+        fs::create_dir_all(&repo_root).unwrap();
+        std::env::set_current_dir(&repo_root).unwrap();
+
+        // No `.gitcommitcreator.toml` or `.gitcc.toml` yet: falls through to the global config.
+        let config = Config::load(Some(&repo_root), None).unwrap();
+        assert_eq!(config.default_commit_prefix, None);
+
+        // A legacy `.gitcommitcreator.toml` at repo_root replaces the config wholesale.
+        fs::write(
+            repo_root.join(".gitcommitcreator.toml"),
+            "commit_prefixes = [\"legacy:\"]\nbranch_prefixes = [\"legacy\"]\ndefault_commit_prefix = \"legacy:\"\n",
+        )
+        .unwrap();
+        let config = Config::load(Some(&repo_root), None).unwrap();
+        assert_eq!(config.commit_prefixes, vec!["legacy:".to_string()]);
+        assert_eq!(config.default_commit_prefix, Some("legacy:".to_string()));
+
+        // A `.gitcc.toml` overlay still applies on top of the legacy file.
+        fs::write(
+            repo_root.join(".gitcc.toml"),
+            "default_commit_prefix = \"overlay:\"\n",
+        )
+        .unwrap();
+        let config = Config::load(Some(&repo_root), None).unwrap();
+        assert_eq!(config.commit_prefixes, vec!["legacy:".to_string()]);
+        assert_eq!(config.default_commit_prefix, Some("overlay:".to_string()));
+
+        std::env::set_current_dir(&original_dir).unwrap();
+        match original_gitcc_config {
+            Some(value) => std::env::set_var("GITCC_CONFIG", value),
+            None => std::env::remove_var("GITCC_CONFIG"),
+        }
+        fs::remove_dir_all(&tmp).ok();
     }
 }
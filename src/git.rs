@@ -1,7 +1,10 @@
 use anyhow::{Context, Result};
 use git2::{Repository, Status, StatusOptions};
 use std::collections::HashMap;
-use std::process::Command;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
 
 type FileStatusMap = HashMap<String, FileStatus>;
 
@@ -33,24 +36,40 @@ pub fn has_changes(repo: &Repository) -> Result<bool> {
     Ok(true)
 }
 
-/// File status indicator
+/// One side (index or worktree) of a file's two-character porcelain status
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum FileStatus {
+pub enum StatusChar {
     Added,
     Modified,
     Deleted,
+    Renamed,
+    Untracked,
+    Conflicted,
+    Unchanged,
 }
 
-impl FileStatus {
+impl StatusChar {
     pub fn as_str(&self) -> &str {
         match self {
-            FileStatus::Added => "A",
-            FileStatus::Modified => "M",
-            FileStatus::Deleted => "D",
+            StatusChar::Added => "A",
+            StatusChar::Modified => "M",
+            StatusChar::Deleted => "D",
+            StatusChar::Renamed => "R",
+            StatusChar::Untracked => "?",
+            StatusChar::Conflicted => "U",
+            StatusChar::Unchanged => " ",
         }
     }
 }
 
+/// A file's status split into its index (staged) side and worktree (unstaged) side,
+/// mirroring the two columns of `git status --porcelain`
+#[derive(Debug, Clone, Copy)]
+pub struct FileStatus {
+    pub index: StatusChar,
+    pub worktree: StatusChar,
+}
+
 /// Returns all changed files (staged and unstaged), list of staged files, and file statuses
 pub fn get_all_changed_files(
     repo: &Repository,
@@ -72,24 +91,53 @@ pub fn get_all_changed_files(
                 Status::WT_NEW
                     | Status::WT_MODIFIED
                     | Status::WT_DELETED
+                    | Status::WT_RENAMED
                     | Status::INDEX_NEW
                     | Status::INDEX_MODIFIED
-                    | Status::INDEX_DELETED,
+                    | Status::INDEX_DELETED
+                    | Status::INDEX_RENAMED
+                    | Status::CONFLICTED,
             ) {
                 all_files.push(path_str.clone());
 
-                let file_status = if status.intersects(Status::WT_NEW | Status::INDEX_NEW) {
-                    FileStatus::Added
-                } else if status.intersects(Status::WT_DELETED | Status::INDEX_DELETED) {
-                    FileStatus::Deleted
+                let conflicted = status.intersects(Status::CONFLICTED);
+                let index = if conflicted {
+                    StatusChar::Conflicted
+                } else if status.intersects(Status::INDEX_NEW) {
+                    StatusChar::Added
+                } else if status.intersects(Status::INDEX_RENAMED) {
+                    StatusChar::Renamed
+                } else if status.intersects(Status::INDEX_DELETED) {
+                    StatusChar::Deleted
+                } else if status.intersects(Status::INDEX_MODIFIED) {
+                    StatusChar::Modified
                 } else {
-                    FileStatus::Modified
+                    StatusChar::Unchanged
                 };
-                file_statuses.insert(path_str.clone(), file_status);
+
+                let worktree = if conflicted {
+                    StatusChar::Conflicted
+                } else if status.intersects(Status::WT_NEW) {
+                    StatusChar::Untracked
+                } else if status.intersects(Status::WT_RENAMED) {
+                    StatusChar::Renamed
+                } else if status.intersects(Status::WT_DELETED) {
+                    StatusChar::Deleted
+                } else if status.intersects(Status::WT_MODIFIED) {
+                    StatusChar::Modified
+                } else {
+                    StatusChar::Unchanged
+                };
+
+                file_statuses.insert(path_str.clone(), FileStatus { index, worktree });
             }
 
-            if status.intersects(Status::INDEX_NEW | Status::INDEX_MODIFIED | Status::INDEX_DELETED)
-            {
+            if status.intersects(
+                Status::INDEX_NEW
+                    | Status::INDEX_MODIFIED
+                    | Status::INDEX_DELETED
+                    | Status::INDEX_RENAMED,
+            ) {
                 staged_files.push(path_str);
             }
         }
@@ -135,83 +183,237 @@ pub fn stage_files(extensions: Option<String>, directory: Option<String>) -> Res
     Ok(())
 }
 
-/// Builds final commit message
-pub fn build_commit_message(prefix: &str, message: &str) -> Result<String> {
-    Ok(format!("{prefix} {message}"))
+/// Builds final commit message, emitting `type(scope): message` when a scope is given
+/// and `type: message` otherwise
+pub fn build_commit_message(prefix: &str, scope: Option<&str>, message: &str) -> Result<String> {
+    let commit_type = prefix.trim_end_matches(':');
+
+    let formatted_prefix = match scope {
+        Some(scope) if !scope.is_empty() => format!("{commit_type}({scope}):"),
+        _ => format!("{commit_type}:"),
+    };
+
+    Ok(format!("{formatted_prefix} {message}"))
+}
+
+/// Resolves `template_path` (expanding a leading `~` and any `$VAR`/`${VAR}` references)
+/// and, if it names an existing file, returns its contents with `{prefix}`/`{story}`
+/// placeholders substituted. A path that doesn't resolve to a file is a no-op
+/// (`Ok(None)`), not an error; only a file that exists but can't be read is an error
+pub fn render_commit_template(
+    template_path: &str,
+    prefix: &str,
+    story: Option<&str>,
+) -> Result<Option<String>> {
+    let path = expand_path(template_path);
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let template = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read commit template at {}", path.display()))?;
+
+    Ok(Some(
+        template
+            .replace("{prefix}", prefix)
+            .replace("{story}", story.unwrap_or("")),
+    ))
 }
 
-/// Creates commit with message and optionally pushes to remote
-pub fn commit_and_push(commit_msg: &str, no_push: bool) -> Result<()> {
-    let output = Command::new("git")
-        .args(["commit", "-m", commit_msg])
-        .output()?;
+/// Expands a leading `~` (or `~/...`) to the user's home directory and any `$VAR`/
+/// `${VAR}` references using the current environment, leaving anything it can't
+/// resolve untouched
+fn expand_path(raw: &str) -> PathBuf {
+    let with_env = expand_env_vars(raw);
 
-    if !output.status.success() {
-        anyhow::bail!(
-            "Failed to commit: {}",
-            String::from_utf8_lossy(&output.stderr)
-        );
+    if let Some(rest) = with_env.strip_prefix("~/") {
+        if let Some(home) = dirs::home_dir() {
+            return home.join(rest);
+        }
+    } else if with_env == "~" {
+        if let Some(home) = dirs::home_dir() {
+            return home;
+        }
     }
 
-    println!("✅ Committed: {commit_msg}");
+    PathBuf::from(with_env)
+}
 
-    if !no_push {
-        let output = Command::new("git").arg("push").output()?;
+/// Substitutes `$VAR` and `${VAR}` references from the current environment, leaving
+/// unknown or malformed references as-is rather than erroring
+fn expand_env_vars(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
 
-        if !output.status.success() {
-            anyhow::bail!(
-                "Failed to push: {}",
-                String::from_utf8_lossy(&output.stderr)
-            );
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            result.push(c);
+            continue;
         }
 
-        println!("🚀 Pushed to remote");
+        if chars.peek() == Some(&'{') {
+            chars.next();
+            let mut name = String::new();
+            let mut closed = false;
+            for inner in chars.by_ref() {
+                if inner == '}' {
+                    closed = true;
+                    break;
+                }
+                name.push(inner);
+            }
+            match std::env::var(&name) {
+                Ok(value) if closed => result.push_str(&value),
+                _ => {
+                    result.push_str("${");
+                    result.push_str(&name);
+                    if closed {
+                        result.push('}');
+                    }
+                }
+            }
+        } else {
+            let mut name = String::new();
+            while let Some(&next) = chars.peek() {
+                if next.is_alphanumeric() || next == '_' {
+                    name.push(next);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            if name.is_empty() {
+                result.push('$');
+            } else {
+                match std::env::var(&name) {
+                    Ok(value) => result.push_str(&value),
+                    Err(_) => {
+                        result.push('$');
+                        result.push_str(&name);
+                    }
+                }
+            }
+        }
     }
 
-    Ok(())
+    result
 }
 
-/// Stages a single file
-pub fn stage_file(file_path: &str) -> Result<()> {
-    let output = Command::new("git").args(["add", file_path]).output()?;
+/// Creates a commit from the current index and optionally pushes the current branch
+pub fn commit_and_push(repo: &Repository, commit_msg: &str, no_push: bool) -> Result<()> {
+    let mut index = repo.index()?;
+    let tree_oid = index.write_tree()?;
+    let tree = repo.find_tree(tree_oid)?;
+
+    let signature = repo.signature()?;
+    let parent_commit = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent_commit.iter().collect();
+
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        commit_msg,
+        &tree,
+        &parents,
+    )
+    .context("Failed to commit")?;
 
-    if !output.status.success() {
-        anyhow::bail!(
-            "Failed to stage file: {}",
-            String::from_utf8_lossy(&output.stderr)
-        );
+    println!("✅ Committed: {commit_msg}");
+
+    if !no_push {
+        push_current_branch(repo)?;
+        println!("🚀 Pushed to remote");
     }
 
     Ok(())
 }
 
-/// Unstages a single file
-pub fn unstage_file(file_path: &str) -> Result<()> {
-    let output = Command::new("git")
-        .args(["reset", "HEAD", "--", file_path])
-        .output()?;
+/// Pushes the current branch to its remote, trying the ssh-agent and the git
+/// credential helper for authentication
+pub fn push_current_branch(repo: &Repository) -> Result<()> {
+    push_current_branch_impl(repo, false)
+}
 
-    if !output.status.success() {
-        anyhow::bail!(
-            "Failed to unstage file: {}",
-            String::from_utf8_lossy(&output.stderr)
-        );
-    }
+/// Force-pushes the current branch to its remote, for use after the user has
+/// explicitly confirmed rewriting a commit that's already on the upstream (see
+/// `confirm_pushed_action` in `key_handler.rs`) - a plain push would always be
+/// rejected as non-fast-forward in that case
+pub fn force_push_current_branch(repo: &Repository) -> Result<()> {
+    push_current_branch_impl(repo, true)
+}
+
+fn push_current_branch_impl(repo: &Repository, force: bool) -> Result<()> {
+    let head = repo.head()?;
+    let branch_name = head
+        .shorthand()
+        .context("Could not determine current branch")?;
+    let refspec = if force {
+        format!("+refs/heads/{branch_name}:refs/heads/{branch_name}")
+    } else {
+        format!("refs/heads/{branch_name}:refs/heads/{branch_name}")
+    };
+
+    let branch = repo.find_branch(branch_name, git2::BranchType::Local)?;
+    let remote_name = branch
+        .upstream()
+        .ok()
+        .and_then(|upstream| {
+            upstream
+                .get()
+                .name()
+                .and_then(|name| repo.branch_remote_name(name).ok())
+        })
+        .and_then(|buf| buf.as_str().map(|s| s.to_string()))
+        .unwrap_or_else(|| "origin".to_string());
+
+    let mut remote = repo.find_remote(&remote_name)?;
+
+    let mut callbacks = git2::RemoteCallbacks::new();
+    callbacks.credentials(|url, username_from_url, allowed_types| {
+        if allowed_types.contains(git2::CredentialType::SSH_KEY) {
+            if let Some(username) = username_from_url {
+                if let Ok(cred) = git2::Cred::ssh_key_from_agent(username) {
+                    return Ok(cred);
+                }
+            }
+        }
+        git2::Cred::credential_helper(&git2::Config::open_default()?, url, username_from_url)
+    });
+
+    let mut push_options = git2::PushOptions::new();
+    push_options.remote_callbacks(callbacks);
+
+    remote
+        .push(&[refspec.as_str()], Some(&mut push_options))
+        .context("Failed to push")?;
 
     Ok(())
 }
 
-/// Unstages all currently staged files
-pub fn unstage_all() -> Result<()> {
-    let output = Command::new("git").args(["reset", "HEAD", "--"]).output()?;
+/// Stages a single file in the index
+pub fn stage_file(repo: &Repository, file_path: &str) -> Result<()> {
+    let mut index = repo.index()?;
+    index
+        .add_path(std::path::Path::new(file_path))
+        .context("Failed to stage file")?;
+    index.write()?;
+    Ok(())
+}
 
-    if !output.status.success() {
-        anyhow::bail!(
-            "Failed to unstage: {}",
-            String::from_utf8_lossy(&output.stderr)
-        );
-    }
+/// Unstages a single file, resetting it to its HEAD content in the index
+pub fn unstage_file(repo: &Repository, file_path: &str) -> Result<()> {
+    let head = repo.head()?.peel_to_commit()?;
+    repo.reset_default(Some(head.as_object()), [file_path])
+        .context("Failed to unstage file")?;
+    Ok(())
+}
 
+/// Unstages all currently staged files
+pub fn unstage_all(repo: &Repository) -> Result<()> {
+    let head = repo.head()?.peel_to_commit()?;
+    repo.reset(head.as_object(), git2::ResetType::Mixed, None)
+        .context("Failed to unstage")?;
     Ok(())
 }
 
@@ -231,27 +433,17 @@ pub fn build_branch_name(
     Ok(branch_name)
 }
 
-/// Creates a new branch and checks it out
-pub fn create_and_checkout_branch(branch_name: &str) -> Result<()> {
-    let output = Command::new("git").args(["branch", branch_name]).output()?;
+/// Creates a new branch pointing at HEAD and checks it out
+pub fn create_and_checkout_branch(repo: &Repository, branch_name: &str) -> Result<()> {
+    let head_commit = repo.head()?.peel_to_commit()?;
+    repo.branch(branch_name, &head_commit, false)
+        .context("Failed to create branch")?;
 
-    if !output.status.success() {
-        anyhow::bail!(
-            "Failed to create branch: {}",
-            String::from_utf8_lossy(&output.stderr)
-        );
-    }
-
-    let output = Command::new("git")
-        .args(["checkout", branch_name])
-        .output()?;
-
-    if !output.status.success() {
-        anyhow::bail!(
-            "Failed to checkout branch: {}",
-            String::from_utf8_lossy(&output.stderr)
-        );
-    }
+    let branch_ref = format!("refs/heads/{branch_name}");
+    let object = repo.revparse_single(&branch_ref)?;
+    repo.checkout_tree(&object, None)
+        .context("Failed to checkout branch")?;
+    repo.set_head(&branch_ref)?;
 
     println!("✅ Created and checked out branch: {branch_name}");
     Ok(())
@@ -324,3 +516,451 @@ pub fn get_file_diff(file_path: &str, is_staged: bool) -> Result<String> {
         Ok(diff)
     }
 }
+
+/// A recent commit's short id, subject, and author, for the commit-history picker
+#[derive(Debug, Clone)]
+pub struct RecentCommit {
+    pub id: git2::Oid,
+    pub short_id: String,
+    pub subject: String,
+    pub author: String,
+}
+
+/// Returns the last `limit` commits reachable from HEAD, most recent first
+pub fn recent_commits(repo: &Repository, limit: usize) -> Result<Vec<RecentCommit>> {
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push_head()?;
+
+    let mut commits = Vec::new();
+    for oid in revwalk.take(limit) {
+        let oid = oid?;
+        let commit = repo.find_commit(oid)?;
+        commits.push(RecentCommit {
+            id: oid,
+            short_id: oid.to_string().chars().take(7).collect(),
+            subject: commit.summary().unwrap_or("").to_string(),
+            author: commit.author().name().unwrap_or("unknown").to_string(),
+        });
+    }
+
+    Ok(commits)
+}
+
+/// Returns true if `commit_id` is already reachable from the current branch's upstream,
+/// i.e. rewriting it would rewrite history other clones already have
+pub fn is_commit_pushed(repo: &Repository, commit_id: git2::Oid) -> Result<bool> {
+    let head = repo.head()?;
+    let Some(branch_name) = head.shorthand() else {
+        return Ok(false);
+    };
+    let Ok(local_branch) = repo.find_branch(branch_name, git2::BranchType::Local) else {
+        return Ok(false);
+    };
+    let Ok(upstream) = local_branch.upstream() else {
+        return Ok(false);
+    };
+    let Some(upstream_oid) = upstream.get().target() else {
+        return Ok(false);
+    };
+
+    if upstream_oid == commit_id {
+        return Ok(true);
+    }
+
+    Ok(repo
+        .graph_descendant_of(upstream_oid, commit_id)
+        .unwrap_or(false))
+}
+
+/// Amends HEAD, replacing its tree with the current index and its message with `message`
+pub fn amend_commit(repo: &Repository, message: &str) -> Result<()> {
+    let head_commit = repo.head()?.peel_to_commit()?;
+    let mut index = repo.index()?;
+    let tree_oid = index.write_tree()?;
+    let tree = repo.find_tree(tree_oid)?;
+    let signature = repo.signature()?;
+
+    head_commit
+        .amend(
+            Some("HEAD"),
+            Some(&signature),
+            Some(&signature),
+            None,
+            Some(message),
+            Some(&tree),
+        )
+        .context("Failed to amend commit")?;
+
+    println!("✅ Amended commit: {message}");
+    Ok(())
+}
+
+/// Rewords HEAD's message only, leaving its tree and authorship untouched
+pub fn reword_commit(repo: &Repository, message: &str) -> Result<()> {
+    let head_commit = repo.head()?.peel_to_commit()?;
+    head_commit
+        .amend(Some("HEAD"), None, None, None, Some(message), None)
+        .context("Failed to reword commit")?;
+
+    println!("✅ Reworded commit: {message}");
+    Ok(())
+}
+
+/// A request to (re)compute the diff/hunks for a file, tagged with a generation id so
+/// the event loop can discard any response superseded by a newer request
+pub struct DiffRequest {
+    pub id: u64,
+    pub file: String,
+    pub is_staged: bool,
+}
+
+/// The diff/hunks computed in response to a `DiffRequest`
+pub struct DiffResult {
+    pub id: u64,
+    pub current_diff: String,
+    pub file_diff: Option<FileDiff>,
+}
+
+/// Spawns a background thread that computes diffs off the UI thread. Returns a sender
+/// for requests and a receiver for results; `run_app` drains the receiver each tick and
+/// only applies a result whose id still matches the latest request. Before computing,
+/// the worker also drains any requests still sitting in the channel and keeps only the
+/// newest one, so rapidly moving the file cursor cancels stale queued jobs instead of
+/// working through the whole backlog in FIFO order.
+pub fn spawn_diff_worker() -> (
+    std::sync::mpsc::Sender<DiffRequest>,
+    std::sync::mpsc::Receiver<DiffResult>,
+) {
+    let (request_tx, request_rx) = std::sync::mpsc::channel::<DiffRequest>();
+    let (result_tx, result_rx) = std::sync::mpsc::channel::<DiffResult>();
+
+    std::thread::spawn(move || {
+        while let Ok(mut request) = request_rx.recv() {
+            while let Ok(newer) = request_rx.try_recv() {
+                request = newer;
+            }
+
+            let current_diff =
+                get_file_diff(&request.file, request.is_staged).unwrap_or_default();
+            let file_diff = get_file_hunks(&request.file, request.is_staged).ok();
+            if result_tx
+                .send(DiffResult {
+                    id: request.id,
+                    current_diff,
+                    file_diff,
+                })
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+
+    (request_tx, result_rx)
+}
+
+/// A local or remote branch, with its ahead/behind counts relative to its upstream
+#[derive(Debug, Clone)]
+pub struct BranchInfo {
+    pub name: String,
+    pub is_head: bool,
+    pub upstream: Option<String>,
+    pub ahead: usize,
+    pub behind: usize,
+}
+
+/// Lists local branches (with upstream ahead/behind counts), followed by any remote
+/// branches that don't already have a local counterpart
+pub fn get_branches_info(repo: &Repository) -> Result<Vec<BranchInfo>> {
+    let mut branches = Vec::new();
+    let mut local_names = std::collections::HashSet::new();
+
+    for branch in repo.branches(Some(git2::BranchType::Local))? {
+        let (branch, _) = branch?;
+        let name = branch.name()?.unwrap_or_default().to_string();
+        local_names.insert(name.clone());
+
+        let mut upstream_name = None;
+        let mut ahead = 0;
+        let mut behind = 0;
+
+        if let (Some(local_oid), Ok(upstream)) = (branch.get().target(), branch.upstream()) {
+            upstream_name = upstream.name()?.map(|s| s.to_string());
+            if let Some(upstream_oid) = upstream.get().target() {
+                if let Ok((a, b)) = repo.graph_ahead_behind(local_oid, upstream_oid) {
+                    ahead = a;
+                    behind = b;
+                }
+            }
+        }
+
+        branches.push(BranchInfo {
+            name,
+            is_head: branch.is_head(),
+            upstream: upstream_name,
+            ahead,
+            behind,
+        });
+    }
+
+    for branch in repo.branches(Some(git2::BranchType::Remote))? {
+        let (branch, _) = branch?;
+        let name = branch.name()?.unwrap_or_default().to_string();
+        let short_name = name.splitn(2, '/').nth(1).unwrap_or(&name);
+        if local_names.contains(short_name) {
+            continue;
+        }
+
+        branches.push(BranchInfo {
+            name,
+            is_head: false,
+            upstream: None,
+            ahead: 0,
+            behind: 0,
+        });
+    }
+
+    Ok(branches)
+}
+
+/// Checks out an existing local branch, or - for a remote-only branch - creates a
+/// matching local tracking branch first
+pub fn checkout_branch(repo: &Repository, branch_name: &str) -> Result<()> {
+    if repo
+        .find_branch(branch_name, git2::BranchType::Local)
+        .is_ok()
+    {
+        let branch_ref = format!("refs/heads/{branch_name}");
+        let object = repo.revparse_single(&branch_ref)?;
+        repo.checkout_tree(&object, None)
+            .context("Failed to checkout branch")?;
+        repo.set_head(&branch_ref)?;
+        println!("✅ Checked out branch: {branch_name}");
+        return Ok(());
+    }
+
+    if let Ok(remote_branch) = repo.find_branch(branch_name, git2::BranchType::Remote) {
+        let short_name = branch_name.splitn(2, '/').nth(1).unwrap_or(branch_name);
+        let commit = remote_branch.get().peel_to_commit()?;
+
+        let mut local_branch = repo
+            .branch(short_name, &commit, false)
+            .context("Failed to create local tracking branch")?;
+        local_branch.set_upstream(Some(branch_name))?;
+
+        let branch_ref = format!("refs/heads/{short_name}");
+        let object = repo.revparse_single(&branch_ref)?;
+        repo.checkout_tree(&object, None)
+            .context("Failed to checkout branch")?;
+        repo.set_head(&branch_ref)?;
+
+        println!(
+            "✅ Created local branch '{short_name}' tracking '{branch_name}' and checked it out"
+        );
+        return Ok(());
+    }
+
+    anyhow::bail!("Branch not found: {branch_name}")
+}
+
+/// The commit that last touched a contiguous run of lines in a blamed file
+#[derive(Debug, Clone)]
+pub struct BlameHunk {
+    pub commit_id: git2::Oid,
+    pub author: String,
+    pub time: i64,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+/// A file's lines, each annotated with the commit that last touched it (if any)
+#[derive(Debug, Clone)]
+pub struct FileBlame {
+    pub path: String,
+    pub lines: Vec<(Option<BlameHunk>, String)>,
+}
+
+/// Blames a file and pairs each of its lines with the commit that last touched it. Runs
+/// the blame against the on-disk working copy (via `Blame::blame_buffer`) rather than
+/// HEAD alone, so lines shifted by an uncommitted insertion/deletion still line up with
+/// the right commit, and lines that are themselves uncommitted show up unattributed.
+pub fn get_file_blame(repo: &Repository, file_path: &str) -> Result<FileBlame> {
+    let content = std::fs::read_to_string(file_path).unwrap_or_default();
+    let source_lines: Vec<String> = content.lines().map(|line| line.to_string()).collect();
+
+    let committed_blame = repo.blame_file(std::path::Path::new(file_path), None)?;
+    let blame = committed_blame.blame_buffer(content.as_bytes())?;
+    let mut line_hunks: Vec<Option<BlameHunk>> = vec![None; source_lines.len()];
+
+    for hunk in blame.iter() {
+        let signature = hunk.final_signature();
+        let blame_hunk = BlameHunk {
+            commit_id: hunk.final_commit_id(),
+            author: signature.name().unwrap_or("unknown").to_string(),
+            time: signature.when().seconds(),
+            // final_start_line() is 1-based; subtract one to index into line_hunks
+            start_line: hunk.final_start_line().saturating_sub(1),
+            end_line: hunk.final_start_line().saturating_sub(1) + hunk.lines_in_hunk() - 1,
+        };
+
+        for line in blame_hunk.start_line..=blame_hunk.end_line {
+            if let Some(slot) = line_hunks.get_mut(line) {
+                *slot = Some(blame_hunk.clone());
+            }
+        }
+    }
+
+    let lines = source_lines.into_iter().zip(line_hunks).map(|(line, hunk)| (hunk, line)).collect();
+
+    Ok(FileBlame {
+        path: file_path.to_string(),
+        lines,
+    })
+}
+
+/// A single `@@ -a,b +c,d @@` hunk plus the context/+/- lines that follow it
+#[derive(Debug, Clone)]
+pub struct DiffHunk {
+    pub header: String,
+    pub lines: Vec<String>,
+}
+
+/// A file's diff split into its header (`diff --git`/`---`/`+++`) and individual hunks
+#[derive(Debug, Clone)]
+pub struct FileDiff {
+    pub file_header: Vec<String>,
+    pub hunks: Vec<DiffHunk>,
+}
+
+/// Fetches the untruncated diff for a file and splits it into hunks so they can be
+/// staged/unstaged independently
+pub fn get_file_hunks(file_path: &str, is_staged: bool) -> Result<FileDiff> {
+    let mut cmd = Command::new("git");
+    cmd.arg("diff");
+
+    if is_staged {
+        cmd.arg("--cached");
+    }
+
+    cmd.args(["--", file_path]);
+
+    let output = cmd.output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to fetch diff: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let diff = String::from_utf8_lossy(&output.stdout).to_string();
+    Ok(parse_hunks(&diff))
+}
+
+/// Splits a unified diff into its file header and a list of hunks
+fn parse_hunks(diff: &str) -> FileDiff {
+    let mut file_header = Vec::new();
+    let mut hunks: Vec<DiffHunk> = Vec::new();
+
+    for line in diff.lines() {
+        if line.starts_with("@@") {
+            hunks.push(DiffHunk {
+                header: line.to_string(),
+                lines: Vec::new(),
+            });
+        } else if let Some(hunk) = hunks.last_mut() {
+            hunk.lines.push(line.to_string());
+        } else {
+            file_header.push(line.to_string());
+        }
+    }
+
+    FileDiff {
+        file_header,
+        hunks,
+    }
+}
+
+/// Current branch, ahead/behind counts relative to its upstream, and stash count
+#[derive(Debug, Clone, Default)]
+pub struct RepoStatus {
+    pub branch: Option<String>,
+    pub ahead: usize,
+    pub behind: usize,
+    pub stash_count: usize,
+}
+
+/// Gathers branch/ahead/behind/stash context to show as a header before committing
+pub fn get_repo_status(repo: &mut Repository) -> Result<RepoStatus> {
+    let branch = repo
+        .head()
+        .ok()
+        .and_then(|head| head.shorthand().map(|s| s.to_string()));
+
+    let mut ahead = 0;
+    let mut behind = 0;
+
+    if let Some(branch_name) = &branch {
+        if let Ok(local_branch) = repo.find_branch(branch_name, git2::BranchType::Local) {
+            if let Some(local_oid) = local_branch.get().target() {
+                if let Ok(upstream) = local_branch.upstream() {
+                    if let Some(upstream_oid) = upstream.get().target() {
+                        if let Ok((a, b)) = repo.graph_ahead_behind(local_oid, upstream_oid) {
+                            ahead = a;
+                            behind = b;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut stash_count = 0;
+    repo.stash_foreach(|_, _, _| {
+        stash_count += 1;
+        true
+    })?;
+
+    Ok(RepoStatus {
+        branch,
+        ahead,
+        behind,
+        stash_count,
+    })
+}
+
+/// Applies (or reverses) a single hunk against the index by feeding a reconstructed
+/// single-hunk patch to `git apply --cached`
+pub fn apply_hunk(file_header: &[String], hunk: &DiffHunk, reverse: bool) -> Result<()> {
+    let mut patch = file_header.join("\n");
+    patch.push('\n');
+    patch.push_str(&hunk.header);
+    patch.push('\n');
+    patch.push_str(&hunk.lines.join("\n"));
+    patch.push('\n');
+
+    let mut cmd = Command::new("git");
+    cmd.args(["apply", "--cached"]);
+    if reverse {
+        cmd.arg("--reverse");
+    }
+    cmd.stdin(Stdio::piped());
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    let mut child = cmd.spawn()?;
+    child
+        .stdin
+        .take()
+        .context("Failed to open stdin for git apply")?
+        .write_all(patch.as_bytes())?;
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to apply hunk: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    Ok(())
+}
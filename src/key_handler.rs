@@ -1,4 +1,5 @@
 use crossterm::event::{KeyCode, KeyModifiers};
+use git2::Repository;
 
 use crate::{App, AppState};
 
@@ -79,31 +80,104 @@ fn find_prev_word(text: &str, cursor_pos: usize) -> usize {
 }
 
 /// Handles keyboard input based on current application state
-pub fn handle_key(app: &mut App, key: KeyCode, modifiers: KeyModifiers) {
+pub fn handle_key(app: &mut App, key: KeyCode, modifiers: KeyModifiers, repo: &Repository) {
     match app.state {
         AppState::StagedFilesReview => match key {
-            KeyCode::Up => {
+            KeyCode::Tab => {
+                app.diff_focused = !app.diff_focused;
+            }
+            KeyCode::Up if !app.diff_focused => {
                 if app.selected_file_index > 0 {
                     app.selected_file_index -= 1;
+                    app.reload_current_diff();
                 }
             }
-            KeyCode::Down => {
+            KeyCode::Down if !app.diff_focused => {
                 if app.selected_file_index < app.all_files.len().saturating_sub(1) {
                     app.selected_file_index += 1;
+                    app.reload_current_diff();
                 }
             }
-            KeyCode::Enter => {
+            KeyCode::Char('j') | KeyCode::Down if app.diff_focused => {
+                let hunk_count = app.file_diff.as_ref().map_or(0, |d| d.hunks.len());
+                if app.selected_hunk_index < hunk_count.saturating_sub(1) {
+                    app.selected_hunk_index += 1;
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up if app.diff_focused => {
+                if app.selected_hunk_index > 0 {
+                    app.selected_hunk_index -= 1;
+                }
+            }
+            KeyCode::Char('j') => {
+                app.diff_scroll_offset = app.diff_scroll_offset.saturating_add(1);
+            }
+            KeyCode::Char('k') => {
+                app.diff_scroll_offset = app.diff_scroll_offset.saturating_sub(1);
+            }
+            KeyCode::Char('h') => {
+                if let Some(file) = app.all_files.get(app.selected_file_index) {
+                    app.hunk_review_reverse = app.staged_files_set.contains(file);
+                    app.selected_hunk_index = 0;
+                    app.state = AppState::HunkReview;
+                }
+            }
+            KeyCode::Char('b') => {
+                if let Some(file) = app.all_files.get(app.selected_file_index).cloned() {
+                    app.file_blame = crate::git::get_file_blame(repo, &file).ok();
+                    app.diff_scroll_offset = 0;
+                    app.state = AppState::BlameView;
+                }
+            }
+            KeyCode::Char('c') => {
+                if !app.staged_files_set.is_empty() {
+                    app.commits = crate::git::recent_commits(repo, 10).unwrap_or_default();
+                    app.selected_commit_index = 0;
+                    app.confirm_pushed_action = false;
+                    app.state = AppState::CommitHistory;
+                }
+            }
+            KeyCode::Char(' ') if app.diff_focused => {
+                let Some(file) = app.all_files.get(app.selected_file_index).cloned() else {
+                    return;
+                };
+                let is_staged = app.staged_files_set.contains(&file);
+
+                match app.file_diff.clone() {
+                    // Untracked files have no base to diff against - fall back to whole-file staging
+                    Some(diff) if !diff.hunks.is_empty() => {
+                        if let Some(hunk) = diff.hunks.get(app.selected_hunk_index) {
+                            if crate::git::apply_hunk(&diff.file_header, hunk, is_staged).is_ok() {
+                                app.sync_staged_file(repo, &file);
+                            }
+                            app.reload_current_diff();
+                        }
+                    }
+                    _ => {
+                        if is_staged {
+                            let _ = crate::git::unstage_file(repo, &file);
+                            app.staged_files_set.remove(&file);
+                        } else {
+                            let _ = crate::git::stage_file(repo, &file);
+                            app.staged_files_set.insert(file.clone());
+                        }
+                        app.reload_current_diff();
+                    }
+                }
+            }
+            KeyCode::Char(' ') => {
                 if let Some(file) = app.all_files.get(app.selected_file_index) {
                     if app.staged_files_set.contains(file) {
-                        let _ = crate::git::unstage_file(file);
+                        let _ = crate::git::unstage_file(repo, file);
                         app.staged_files_set.remove(file);
                     } else {
-                        let _ = crate::git::stage_file(file);
+                        let _ = crate::git::stage_file(repo, file);
                         app.staged_files_set.insert(file.clone());
                     }
+                    app.reload_current_diff();
                 }
             }
-            KeyCode::Char('y') | KeyCode::Char('Y') => {
+            KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
                 if app.staged_files_set.is_empty() {
                     // Don't proceed if no files are staged
                     return;
@@ -112,7 +186,10 @@ pub fn handle_key(app: &mut App, key: KeyCode, modifiers: KeyModifiers) {
                 if app.prefix.is_some() && app.message.is_some() {
                     app.should_quit = true;
                 } else if app.prefix.is_some() {
-                    app.state = AppState::MessageInput;
+                    let staged: Vec<String> = app.staged_files_set.iter().cloned().collect();
+                    app.scope_input = crate::scope::suggest_scope(&staged).unwrap_or_default();
+                    app.cursor_position = app.scope_input.len();
+                    app.state = AppState::ScopeInput;
                 } else {
                     app.filter.clear();
                     app.selected_prefix_index = 0;
@@ -150,7 +227,10 @@ pub fn handle_key(app: &mut App, key: KeyCode, modifiers: KeyModifiers) {
                     if app.message.is_some() {
                         app.should_quit = true;
                     } else {
-                        app.state = AppState::MessageInput;
+                        let staged: Vec<String> = app.staged_files_set.iter().cloned().collect();
+                        app.scope_input = crate::scope::suggest_scope(&staged).unwrap_or_default();
+                        app.cursor_position = app.scope_input.len();
+                        app.state = AppState::ScopeInput;
                     }
                 }
             }
@@ -167,6 +247,64 @@ pub fn handle_key(app: &mut App, key: KeyCode, modifiers: KeyModifiers) {
             KeyCode::Esc => app.should_quit = true,
             _ => {}
         },
+        AppState::ScopeInput => match key {
+            KeyCode::Enter => {
+                app.scope = if app.scope_input.trim().is_empty() {
+                    None
+                } else {
+                    Some(app.scope_input.trim().to_string())
+                };
+
+                if app.commit_message.is_empty() {
+                    if let Some(template_path) = app.config.commit_template.clone() {
+                        let prefix = app.prefix.clone().unwrap_or_default();
+                        if let Ok(Some(rendered)) = crate::git::render_commit_template(
+                            &template_path,
+                            &prefix,
+                            app.story.as_deref(),
+                        ) {
+                            app.commit_message = rendered;
+                        }
+                    }
+                }
+
+                app.cursor_position = app.commit_message.len();
+                app.state = AppState::MessageInput;
+            }
+            KeyCode::Char(c) => {
+                app.scope_input.insert(app.cursor_position, c);
+                app.cursor_position += 1;
+            }
+            KeyCode::Backspace => {
+                if app.cursor_position > 0 {
+                    app.cursor_position -= 1;
+                    app.scope_input.remove(app.cursor_position);
+                }
+            }
+            KeyCode::Delete => {
+                if app.cursor_position < app.scope_input.len() {
+                    app.scope_input.remove(app.cursor_position);
+                }
+            }
+            KeyCode::Left => {
+                if app.cursor_position > 0 {
+                    app.cursor_position -= 1;
+                }
+            }
+            KeyCode::Right => {
+                if app.cursor_position < app.scope_input.len() {
+                    app.cursor_position += 1;
+                }
+            }
+            KeyCode::Home => {
+                app.cursor_position = 0;
+            }
+            KeyCode::End => {
+                app.cursor_position = app.scope_input.len();
+            }
+            KeyCode::Esc => app.should_quit = true,
+            _ => {}
+        },
         AppState::MessageInput => match key {
             KeyCode::Enter => {
                 if !app.commit_message.trim().is_empty() {
@@ -345,5 +483,201 @@ pub fn handle_key(app: &mut App, key: KeyCode, modifiers: KeyModifiers) {
             KeyCode::Esc => app.should_quit = true,
             _ => {}
         },
+        AppState::ProjectSelection => match key {
+            KeyCode::Up => {
+                if app.selected_project_index > 0 {
+                    app.selected_project_index -= 1;
+                }
+            }
+            KeyCode::Down => {
+                if app.selected_project_index < app.project_groups.len().saturating_sub(1) {
+                    app.selected_project_index += 1;
+                }
+            }
+            KeyCode::Char(' ') => {
+                if app.selected_projects.contains(&app.selected_project_index) {
+                    app.selected_projects.remove(&app.selected_project_index);
+                } else {
+                    app.selected_projects.insert(app.selected_project_index);
+                }
+            }
+            KeyCode::Char('a') => {
+                if app.selected_projects.len() == app.project_groups.len() {
+                    app.selected_projects.clear();
+                } else {
+                    app.selected_projects = (0..app.project_groups.len()).collect();
+                }
+            }
+            KeyCode::Enter => {
+                if !app.selected_projects.is_empty() {
+                    app.should_proceed = true;
+                    app.should_quit = true;
+                }
+            }
+            KeyCode::Esc => app.should_quit = true,
+            _ => {}
+        },
+        AppState::HunkReview => match key {
+            KeyCode::Up => {
+                if app.selected_hunk_index > 0 {
+                    app.selected_hunk_index -= 1;
+                }
+            }
+            KeyCode::Down => {
+                let len = app.file_diff.as_ref().map_or(0, |d| d.hunks.len());
+                if app.selected_hunk_index < len.saturating_sub(1) {
+                    app.selected_hunk_index += 1;
+                }
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                let Some(file) = app.all_files.get(app.selected_file_index).cloned() else {
+                    return;
+                };
+                let reverse = app.hunk_review_reverse;
+                if let Some(diff) = app.file_diff.clone() {
+                    if let Some(hunk) = diff.hunks.get(app.selected_hunk_index) {
+                        if crate::git::apply_hunk(&diff.file_header, hunk, reverse).is_ok() {
+                            // Re-fetch rather than reuse the stale hunk list: applying a hunk
+                            // shifts the line offsets of any hunks still further down the file
+                            if let Ok(new_diff) = crate::git::get_file_hunks(&file, reverse) {
+                                app.selected_hunk_index = app
+                                    .selected_hunk_index
+                                    .min(new_diff.hunks.len().saturating_sub(1));
+                                app.file_diff = Some(new_diff);
+                            }
+                        }
+                    }
+                }
+                app.sync_staged_file(repo, &file);
+            }
+            KeyCode::Char('a') => {
+                let Some(file) = app.all_files.get(app.selected_file_index).cloned() else {
+                    return;
+                };
+                let reverse = app.hunk_review_reverse;
+                while let Some(diff) = app.file_diff.clone() {
+                    let Some(hunk) = diff.hunks.first() else {
+                        break;
+                    };
+                    if crate::git::apply_hunk(&diff.file_header, hunk, reverse).is_err() {
+                        break;
+                    }
+                    match crate::git::get_file_hunks(&file, reverse) {
+                        Ok(new_diff) => app.file_diff = Some(new_diff),
+                        Err(_) => break,
+                    }
+                }
+                app.selected_hunk_index = 0;
+                app.sync_staged_file(repo, &file);
+            }
+            KeyCode::Esc => {
+                app.state = AppState::StagedFilesReview;
+                app.reload_current_diff();
+            }
+            _ => {}
+        },
+        AppState::BranchList => match key {
+            KeyCode::Up => {
+                let filtered = app.filtered_branches();
+                if app.selected_branch_index > 0 {
+                    app.selected_branch_index -= 1;
+                } else {
+                    app.selected_branch_index = filtered.len().saturating_sub(1);
+                }
+            }
+            KeyCode::Down => {
+                let filtered = app.filtered_branches();
+                if app.selected_branch_index < filtered.len().saturating_sub(1) {
+                    app.selected_branch_index += 1;
+                } else {
+                    app.selected_branch_index = 0;
+                }
+            }
+            KeyCode::Enter => {
+                let filtered = app.filtered_branches();
+                if !filtered.is_empty() && app.selected_branch_index < filtered.len() {
+                    app.selected_checkout_branch =
+                        Some(filtered[app.selected_branch_index].name.clone());
+                    app.should_proceed = true;
+                    app.should_quit = true;
+                }
+            }
+            KeyCode::Char(c) => {
+                app.filter.push(c);
+                app.selected_branch_index = 0;
+            }
+            KeyCode::Backspace => {
+                if !app.filter.is_empty() {
+                    app.filter.pop();
+                    app.selected_branch_index = 0;
+                }
+            }
+            KeyCode::Esc => app.should_quit = true,
+            _ => {}
+        },
+        AppState::CommitHistory => match key {
+            KeyCode::Up => {
+                if app.selected_commit_index > 0 {
+                    app.selected_commit_index -= 1;
+                    app.confirm_pushed_action = false;
+                }
+            }
+            KeyCode::Down => {
+                if app.selected_commit_index < app.commits.len().saturating_sub(1) {
+                    app.selected_commit_index += 1;
+                    app.confirm_pushed_action = false;
+                }
+            }
+            KeyCode::Char('f') => {
+                if let Some(target) = app.commits.get(app.selected_commit_index).cloned() {
+                    app.commit_action = Some(crate::CommitAction::Fixup);
+                    app.target_commit = Some(target);
+                    app.should_proceed = true;
+                    app.should_quit = true;
+                }
+            }
+            KeyCode::Char('a') | KeyCode::Char('r') => {
+                // Only HEAD can be amended/reworded directly; older commits need a rebase
+                if app.selected_commit_index != 0 {
+                    return;
+                }
+                let Some(target) = app.commits.first().cloned() else {
+                    return;
+                };
+
+                let pushed = crate::git::is_commit_pushed(repo, target.id).unwrap_or(false);
+                if pushed && !app.confirm_pushed_action {
+                    app.confirm_pushed_action = true;
+                    return;
+                }
+
+                app.commit_action = Some(if key == KeyCode::Char('a') {
+                    crate::CommitAction::Amend
+                } else {
+                    crate::CommitAction::Reword
+                });
+                app.target_commit = Some(target.clone());
+                app.commit_message = target.subject.clone();
+                app.cursor_position = app.commit_message.len();
+                app.should_proceed = true;
+                app.state = AppState::MessageInput;
+            }
+            KeyCode::Esc => {
+                app.state = AppState::StagedFilesReview;
+            }
+            _ => {}
+        },
+        AppState::BlameView => match key {
+            KeyCode::Up | KeyCode::Char('k') => {
+                app.diff_scroll_offset = app.diff_scroll_offset.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                app.diff_scroll_offset = app.diff_scroll_offset.saturating_add(1);
+            }
+            KeyCode::Esc => {
+                app.state = AppState::StagedFilesReview;
+            }
+            _ => {}
+        },
     }
 }
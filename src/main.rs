@@ -1,10 +1,15 @@
 //! Git Commit Creator (gitcc) - A TUI tool for creating conventional commits
 
+mod config;
 mod git;
 mod key_handler;
+mod project;
+mod scope;
 mod ui;
 
-use anyhow::Result;
+use config::Config;
+
+use anyhow::{Context, Result};
 use clap::Parser;
 use crossterm::{
     event::{self, poll, DisableMouseCapture, EnableMouseCapture, Event},
@@ -12,6 +17,7 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
+use std::fs;
 use std::io;
 use std::time::Duration;
 
@@ -50,24 +56,42 @@ struct Cli {
 
     #[arg(long, help = "Branch name")]
     branch_name: Option<String>,
+
+    #[arg(long, help = "Commit changed files project-by-project in a monorepo")]
+    monorepo: bool,
+
+    #[arg(long, help = "Browse and checkout an existing branch")]
+    checkout: bool,
+
+    #[arg(long, help = "Print the resolved config file path and exit")]
+    print_config_path: bool,
+
+    #[arg(long, help = "Use a named profile from the config file")]
+    profile: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<Commands>,
 }
 
-/// Available conventional commit prefixes
-pub const COMMIT_PREFIXES: &[&str] = &[
-    "feat:",
-    "fix:",
-    "docs:",
-    "style:",
-    "refactor:",
-    "test:",
-    "ci:",
-    "chore:",
-];
-
-/// Available branch prefixes
-pub const BRANCH_PREFIXES: &[&str] = &[
-    "build", "chore", "ci", "docs", "feat", "fix", "perf", "refactor", "revert", "style", "test",
-];
+/// Top-level subcommands, layered on top of the default stage-and-commit flow
+#[derive(clap::Subcommand)]
+enum Commands {
+    /// Get, set, or edit persisted gitcc configuration
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+}
+
+#[derive(clap::Subcommand, Clone)]
+enum ConfigAction {
+    /// Print the value of a config key
+    Get { key: String },
+    /// Set a config key to a value
+    Set { key: String, value: String },
+    /// Open the config file in $EDITOR/$VISUAL and re-validate it on save
+    Edit,
+}
 
 /// Application state for TUI navigation
 #[derive(Debug, Clone)]
@@ -78,6 +102,20 @@ pub enum AppState {
     BranchPrefixSelection,
     BranchStoryInput,
     BranchNameInput,
+    HunkReview,
+    ScopeInput,
+    ProjectSelection,
+    BlameView,
+    BranchList,
+    CommitHistory,
+}
+
+/// What to do with the commit targeted from `AppState::CommitHistory`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitAction {
+    Amend,
+    Fixup,
+    Reword,
 }
 
 /// Main application state
@@ -101,6 +139,38 @@ pub struct App {
     pub branch_story: String,
     pub branch_name: String,
     pub branch_prefix: Option<String>,
+    pub filter: String,
+    pub file_statuses: std::collections::HashMap<String, git::FileStatus>,
+    pub current_diff: String,
+    pub diff_scroll_offset: usize,
+    pub diff_visible_lines: usize,
+    pub file_diff: Option<git::FileDiff>,
+    pub selected_hunk_index: usize,
+    /// Whether the hunks shown in `AppState::HunkReview` come from the staged (cached)
+    /// side of the diff, set when entering that state. `true` means toggling a hunk
+    /// unstages it; `false` means it stages it.
+    pub hunk_review_reverse: bool,
+    pub scope: Option<String>,
+    pub scope_input: String,
+    pub config: Config,
+    pub repo_status: git::RepoStatus,
+    pub project_groups: Vec<(String, Vec<String>)>,
+    pub selected_project_index: usize,
+    pub selected_projects: std::collections::HashSet<usize>,
+    pub diff_focused: bool,
+    pub file_blame: Option<git::FileBlame>,
+    pub branches: Vec<git::BranchInfo>,
+    pub selected_branch_index: usize,
+    pub selected_checkout_branch: Option<String>,
+    pub diff_tx: Option<std::sync::mpsc::Sender<git::DiffRequest>>,
+    pub diff_request_id: u64,
+    pub diff_loading: bool,
+    pub commits: Vec<git::RecentCommit>,
+    pub selected_commit_index: usize,
+    pub commit_action: Option<CommitAction>,
+    pub target_commit: Option<git::RecentCommit>,
+    pub confirm_pushed_action: bool,
+    pub story: Option<String>,
 }
 
 impl App {
@@ -140,22 +210,136 @@ impl App {
             branch_story: String::new(),
             branch_name: String::new(),
             branch_prefix,
+            filter: String::new(),
+            file_statuses: std::collections::HashMap::new(),
+            current_diff: String::new(),
+            diff_scroll_offset: 0,
+            diff_visible_lines: 0,
+            file_diff: None,
+            selected_hunk_index: 0,
+            hunk_review_reverse: false,
+            scope: None,
+            scope_input: String::new(),
+            config: Config::default(),
+            repo_status: git::RepoStatus::default(),
+            project_groups: Vec::new(),
+            selected_project_index: 0,
+            selected_projects: std::collections::HashSet::new(),
+            diff_focused: false,
+            file_blame: None,
+            branches: Vec::new(),
+            selected_branch_index: 0,
+            selected_checkout_branch: None,
+            diff_tx: None,
+            diff_request_id: 0,
+            diff_loading: false,
+            commits: Vec::new(),
+            selected_commit_index: 0,
+            commit_action: None,
+            target_commit: None,
+            confirm_pushed_action: false,
+            story: None,
+        }
+    }
+
+    /// Commit prefixes matching the current filter text
+    pub fn filtered_commit_prefixes(&self) -> Vec<String> {
+        self.config
+            .commit_prefixes
+            .iter()
+            .filter(|p| p.to_lowercase().contains(&self.filter.to_lowercase()))
+            .cloned()
+            .collect()
+    }
+
+    /// Branch prefixes matching the current filter text
+    pub fn filtered_branch_prefixes(&self) -> Vec<String> {
+        self.config
+            .branch_prefixes
+            .iter()
+            .filter(|p| p.to_lowercase().contains(&self.filter.to_lowercase()))
+            .cloned()
+            .collect()
+    }
+
+    /// Branches matching the current filter text
+    pub fn filtered_branches(&self) -> Vec<git::BranchInfo> {
+        self.branches
+            .iter()
+            .filter(|b| b.name.to_lowercase().contains(&self.filter.to_lowercase()))
+            .cloned()
+            .collect()
+    }
+
+    /// Re-derives whether `file` is currently staged from git's index and updates
+    /// `staged_files_set` accordingly. Needed after hunk-level stage/unstage operations,
+    /// which change the index without going through the whole-file space-toggle path.
+    pub fn sync_staged_file(&mut self, repo: &git2::Repository, file: &str) {
+        if let Ok((_, staged_files, _)) = git::get_all_changed_files(repo) {
+            if staged_files.iter().any(|f| f == file) {
+                self.staged_files_set.insert(file.to_string());
+            } else {
+                self.staged_files_set.remove(file);
+            }
         }
     }
+
+    /// Requests a fresh diff/hunks for the currently selected file from the background
+    /// diff worker. Bumps `diff_request_id` so a result for a since-superseded request
+    /// (e.g. the cursor moved again before the worker replied) is discarded on arrival.
+    pub fn reload_current_diff(&mut self) {
+        self.diff_scroll_offset = 0;
+        self.selected_hunk_index = 0;
+        self.diff_request_id += 1;
+
+        let Some(file) = self.all_files.get(self.selected_file_index).cloned() else {
+            self.current_diff.clear();
+            self.file_diff = None;
+            self.diff_loading = false;
+            return;
+        };
+
+        let Some(tx) = &self.diff_tx else {
+            return;
+        };
+
+        let is_staged = self.staged_files_set.contains(&file);
+        self.diff_loading = true;
+        let _ = tx.send(git::DiffRequest {
+            id: self.diff_request_id,
+            file,
+            is_staged,
+        });
+    }
 }
 
 /// Main TUI event loop
-fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, mut app: App) -> Result<App> {
+fn run_app(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    mut app: App,
+    repo: &git2::Repository,
+    diff_rx: &std::sync::mpsc::Receiver<git::DiffResult>,
+) -> Result<App> {
     loop {
+        // Apply the latest completed background diff; drop any result superseded by a
+        // newer request (e.g. the file cursor moved again before this one arrived)
+        while let Ok(result) = diff_rx.try_recv() {
+            if result.id == app.diff_request_id {
+                app.current_diff = result.current_diff;
+                app.file_diff = result.file_diff;
+                app.diff_loading = false;
+            }
+        }
+
         terminal.draw(|f| ui::render(f, &app))?;
 
         if app.should_quit {
             break;
         }
 
-        if poll(Duration::from_millis(500))? {
+        if poll(Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
-                key_handler::handle_key(&mut app, key.code, key.modifiers);
+                key_handler::handle_key(&mut app, key.code, key.modifiers, repo);
             }
         } else {
             // Toggle cursor visibility for blinking effect
@@ -170,12 +354,33 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, mut app: App)
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    if cli.print_config_path {
+        println!("{}", Config::config_path()?.display());
+        return Ok(());
+    }
+
+    if let Some(Commands::Config { action }) = &cli.command {
+        return handle_config_command(action.clone());
+    }
+
     // Handle branch creation mode
     if cli.branch {
         return handle_branch_creation(cli).await;
     }
 
-    let repo = git::ensure_git_repository()?;
+    // Handle per-project monorepo commit mode
+    if cli.monorepo {
+        return handle_monorepo_commit(cli).await;
+    }
+
+    // Handle branch browsing/checkout mode
+    if cli.checkout {
+        return handle_branch_checkout().await;
+    }
+
+    let mut repo = git::ensure_git_repository()?;
+    let config = Config::load(repo.workdir(), cli.profile.as_deref())?;
+    let repo_status = git::get_repo_status(&mut repo)?;
 
     // Check if there are any changes to stage
     if !git::has_changes(&repo)? {
@@ -184,25 +389,41 @@ async fn main() -> Result<()> {
     }
 
     git::stage_files(cli.extensions, cli.directory)?;
-    let (all_files, staged_files) = git::get_all_changed_files(&repo)?;
+    let (all_files, staged_files, file_statuses) = git::get_all_changed_files(&repo)?;
 
     if staged_files.is_empty() {
         println!("❌ No files staged. Aborting.");
         return Ok(());
     }
 
+    let no_push = cli.no_push || !config.auto_push.unwrap_or(true);
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut app = App::new(cli.prefix, cli.message, cli.no_push, false, None);
+    let (diff_tx, diff_rx) = git::spawn_diff_worker();
+
+    let mut app = App::new(
+        cli.prefix.or(config.default_commit_prefix.clone()),
+        cli.message,
+        no_push,
+        false,
+        None,
+    );
+    app.story = cli.story.clone();
     app.all_files = all_files;
     app.staged_files = staged_files.clone();
     app.staged_files_set = staged_files.into_iter().collect();
+    app.file_statuses = file_statuses;
+    app.config = config;
+    app.repo_status = repo_status;
+    app.diff_tx = Some(diff_tx);
+    app.reload_current_diff();
 
-    let result = run_app(&mut terminal, app);
+    let result = run_app(&mut terminal, app, &repo, &diff_rx);
 
     disable_raw_mode()?;
     execute!(
@@ -214,31 +435,155 @@ async fn main() -> Result<()> {
 
     match result {
         Ok(app) => {
-            if app.should_proceed && app.prefix.is_some() && app.message.is_some() {
-                let commit_msg =
-                    git::build_commit_message(&app.prefix.unwrap(), &app.message.unwrap())?;
-                git::commit_and_push(&commit_msg, app.no_push)?;
+            if !app.should_proceed {
+                println!("⏹️ Aborted by user. Unstaging changes...");
+                git::unstage_all(&repo)?;
+            } else if let Some(action) = app.commit_action {
+                let target = app
+                    .target_commit
+                    .context("commit action selected without a target commit")?;
+                match action {
+                    CommitAction::Fixup => {
+                        let fixup_msg = format!("fixup! {}", target.subject);
+                        git::commit_and_push(&repo, &fixup_msg, app.no_push)?;
+                    }
+                    CommitAction::Amend | CommitAction::Reword => {
+                        let Some(message) = app.message else {
+                            println!("⏹️ Aborted by user. Unstaging changes...");
+                            git::unstage_all(&repo)?;
+                            return Ok(());
+                        };
+                        if action == CommitAction::Amend {
+                            git::amend_commit(&repo, &message)?;
+                        } else {
+                            git::reword_commit(&repo, &message)?;
+                        }
+                        if !app.no_push {
+                            if app.confirm_pushed_action {
+                                git::force_push_current_branch(&repo)?;
+                            } else {
+                                git::push_current_branch(&repo)?;
+                            }
+                        }
+                    }
+                }
+            } else if app.prefix.is_some() && app.message.is_some() {
+                let commit_msg = git::build_commit_message(
+                    &app.prefix.unwrap(),
+                    app.scope.as_deref(),
+                    &app.message.unwrap(),
+                )?;
+                git::commit_and_push(&repo, &commit_msg, app.no_push)?;
             } else {
                 println!("⏹️ Aborted by user. Unstaging changes...");
-                git::unstage_all()?;
+                git::unstage_all(&repo)?;
             }
         }
         Err(e) => {
             println!("❌ Error: {e}");
-            git::unstage_all()?;
+            git::unstage_all(&repo)?;
         }
     }
 
     Ok(())
 }
 
+/// Dispatches `gitcc config get/set/edit`
+fn handle_config_command(action: ConfigAction) -> Result<()> {
+    match action {
+        ConfigAction::Get { key } => config_get(&key),
+        ConfigAction::Set { key, value } => config_set(&key, &value),
+        ConfigAction::Edit => config_edit(),
+    }
+}
+
+/// Prints the value of a single key from the user-level config file
+fn config_get(key: &str) -> Result<()> {
+    let path = Config::ensure_exists()?;
+    let content = fs::read_to_string(&path)?;
+    let value: toml::Value = toml::from_str(&content)?;
+
+    match value.get(key) {
+        Some(v) => println!("{v}"),
+        None => println!("<unset>"),
+    }
+
+    Ok(())
+}
+
+/// Sets a single key in the user-level config file, validating the result before
+/// persisting it so a bad value can't silently corrupt the file
+fn config_set(key: &str, raw_value: &str) -> Result<()> {
+    let path = Config::ensure_exists()?;
+    let content = fs::read_to_string(&path)?;
+    let mut value: toml::Value = toml::from_str(&content)?;
+
+    let table = value
+        .as_table_mut()
+        .context("Config file root is not a table")?;
+    table.insert(key.to_string(), parse_config_value(key, raw_value));
+
+    let rendered = toml::to_string_pretty(&value)?;
+    toml::from_str::<Config>(&rendered).context("That value produces an invalid config")?;
+
+    fs::write(&path, rendered)?;
+    println!("✅ Set {key} = {raw_value}");
+    Ok(())
+}
+
+/// Opens the user-level config file in `$VISUAL`/`$EDITOR`, creating it with defaults
+/// first if missing, and re-parses it on save so a bad edit is reported instead of
+/// silently corrupting state
+fn config_edit() -> Result<()> {
+    let path = Config::ensure_exists()?;
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    let status = std::process::Command::new(&editor)
+        .arg(&path)
+        .status()
+        .with_context(|| format!("Failed to launch editor '{editor}'"))?;
+    if !status.success() {
+        anyhow::bail!("Editor '{editor}' exited with a non-zero status");
+    }
+
+    let content = fs::read_to_string(&path)?;
+    toml::from_str::<Config>(&content).context("Config file contains invalid TOML after edit")?;
+    println!("✅ Config saved: {}", path.display());
+    Ok(())
+}
+
+/// Interprets a raw CLI value for `config set`, parsing known list/bool keys and
+/// falling back to a plain string otherwise
+fn parse_config_value(key: &str, raw: &str) -> toml::Value {
+    match key {
+        "commit_prefixes" | "branch_prefixes" | "project_roots" => toml::Value::Array(
+            raw.split(',')
+                .map(|s| toml::Value::String(s.trim().to_string()))
+                .collect(),
+        ),
+        "auto_push" => raw
+            .parse::<bool>()
+            .map(toml::Value::Boolean)
+            .unwrap_or_else(|_| toml::Value::String(raw.to_string())),
+        _ => toml::Value::String(raw.to_string()),
+    }
+}
+
 async fn handle_branch_creation(cli: Cli) -> Result<()> {
-    git::ensure_git_repository()?;
+    let repo = git::ensure_git_repository()?;
+    let config = Config::load(repo.workdir(), cli.profile.as_deref())?;
 
     // If all branch parameters provided via CLI, create directly
     if let (Some(prefix), Some(name)) = (&cli.branch_prefix, &cli.branch_name) {
-        let branch_name = git::build_branch_name(prefix, cli.story.as_deref(), name)?;
-        git::create_and_checkout_branch(&branch_name)?;
+        let branch_name = git::build_branch_name(
+            prefix,
+            cli.story.as_deref(),
+            name,
+            config.story_prefix.as_deref(),
+        )?;
+        git::create_and_checkout_branch(&repo, &branch_name)?;
         return Ok(());
     }
 
@@ -249,6 +594,8 @@ async fn handle_branch_creation(cli: Cli) -> Result<()> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
+    let (diff_tx, diff_rx) = git::spawn_diff_worker();
+
     let mut app = App::new(None, None, false, true, cli.branch_prefix);
     if let Some(story) = cli.story {
         app.branch_story = story;
@@ -256,8 +603,10 @@ async fn handle_branch_creation(cli: Cli) -> Result<()> {
     if let Some(name) = cli.branch_name {
         app.branch_name = name;
     }
+    app.config = config;
+    app.diff_tx = Some(diff_tx);
 
-    let result = run_app(&mut terminal, app);
+    let result = run_app(&mut terminal, app, &repo, &diff_rx);
 
     disable_raw_mode()?;
     execute!(
@@ -275,9 +624,13 @@ async fn handle_branch_creation(cli: Cli) -> Result<()> {
                 } else {
                     Some(app.branch_story.as_str())
                 };
-                let branch_name =
-                    git::build_branch_name(&app.branch_prefix.unwrap(), story, &app.branch_name)?;
-                git::create_and_checkout_branch(&branch_name)?;
+                let branch_name = git::build_branch_name(
+                    &app.branch_prefix.unwrap(),
+                    story,
+                    &app.branch_name,
+                    app.config.story_prefix.as_deref(),
+                )?;
+                git::create_and_checkout_branch(&repo, &branch_name)?;
             } else {
                 println!("⏹️ Branch creation aborted by user.");
             }
@@ -289,3 +642,176 @@ async fn handle_branch_creation(cli: Cli) -> Result<()> {
 
     Ok(())
 }
+
+/// Lets the user browse local and remote branches and checks out the selected one
+async fn handle_branch_checkout() -> Result<()> {
+    let repo = git::ensure_git_repository()?;
+    let branches = git::get_branches_info(&repo)?;
+
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let (diff_tx, diff_rx) = git::spawn_diff_worker();
+
+    let mut app = App::new(None, None, false, false, None);
+    app.branches = branches;
+    app.state = AppState::BranchList;
+    app.diff_tx = Some(diff_tx);
+
+    let result = run_app(&mut terminal, app, &repo, &diff_rx);
+
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    match result {
+        Ok(app) => {
+            if app.should_proceed {
+                if let Some(branch_name) = app.selected_checkout_branch {
+                    git::checkout_branch(&repo, &branch_name)?;
+                }
+            } else {
+                println!("⏹️ Branch checkout aborted by user.");
+            }
+        }
+        Err(e) => {
+            println!("❌ Error: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Lets the user pick which affected projects to commit, then walks each selected
+/// project through its own prefix/scope/message prompt and commit
+async fn handle_monorepo_commit(cli: Cli) -> Result<()> {
+    let repo = git::ensure_git_repository()?;
+    let config = Config::load(repo.workdir(), cli.profile.as_deref())?;
+
+    if !git::has_changes(&repo)? {
+        println!("✨ No changes to commit. Working directory is clean.");
+        return Ok(());
+    }
+
+    git::stage_files(cli.extensions.clone(), cli.directory.clone())?;
+    let (all_files, _staged_files, _file_statuses) = git::get_all_changed_files(&repo)?;
+
+    if all_files.is_empty() {
+        println!("❌ No changed files found. Aborting.");
+        return Ok(());
+    }
+
+    let no_push = cli.no_push || !config.auto_push.unwrap_or(true);
+
+    let roots = config
+        .project_roots
+        .clone()
+        .filter(|roots| !roots.is_empty())
+        .unwrap_or_else(|| project::infer_project_roots(&all_files));
+    let groups = project::partition_by_roots(&all_files, &roots);
+
+    // Unstage everything so each selected project can be staged independently below
+    git::unstage_all(&repo)?;
+
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let (diff_tx, diff_rx) = git::spawn_diff_worker();
+
+    let mut app = App::new(None, None, no_push, false, None);
+    app.config = config.clone();
+    app.project_groups = groups.into_iter().collect();
+    app.state = AppState::ProjectSelection;
+    app.diff_tx = Some(diff_tx);
+
+    let result = run_app(&mut terminal, app, &repo, &diff_rx);
+
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    let selection = result?;
+    if !selection.should_proceed || selection.selected_projects.is_empty() {
+        println!("⏹️ Aborted by user.");
+        return Ok(());
+    }
+
+    let selected_projects: Vec<(String, Vec<String>)> = selection
+        .project_groups
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| selection.selected_projects.contains(i))
+        .map(|(_, group)| group)
+        .collect();
+
+    for (name, files) in selected_projects {
+        for file in &files {
+            git::stage_file(&repo, file)?;
+        }
+
+        println!("📦 Committing project: {name}");
+
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let (project_diff_tx, project_diff_rx) = git::spawn_diff_worker();
+
+        let mut project_app = App::new(
+            cli.prefix.clone().or(config.default_commit_prefix.clone()),
+            None,
+            no_push,
+            false,
+            None,
+        );
+        project_app.config = config.clone();
+        project_app.all_files = files.clone();
+        project_app.staged_files_set = files.iter().cloned().collect();
+        project_app.diff_tx = Some(project_diff_tx);
+        project_app.reload_current_diff();
+
+        let project_result = run_app(&mut terminal, project_app, &repo, &project_diff_rx);
+
+        disable_raw_mode()?;
+        execute!(
+            terminal.backend_mut(),
+            LeaveAlternateScreen,
+            DisableMouseCapture
+        )?;
+        terminal.show_cursor()?;
+
+        let project_app = project_result?;
+        if project_app.should_proceed
+            && project_app.prefix.is_some()
+            && project_app.message.is_some()
+        {
+            let commit_msg = git::build_commit_message(
+                &project_app.prefix.unwrap(),
+                project_app.scope.as_deref(),
+                &project_app.message.unwrap(),
+            )?;
+            git::commit_and_push(&repo, &commit_msg, project_app.no_push)?;
+        } else {
+            println!("⏹️ Skipped project: {name}");
+            git::unstage_all(&repo)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Manifest files used to auto-infer project roots when none are configured
+const MANIFEST_FILES: &[&str] = &[
+    "Cargo.toml",
+    "package.json",
+    "go.mod",
+    "pyproject.toml",
+    "pom.xml",
+];
+
+/// A node in the trie of configured project roots
+#[derive(Default)]
+struct RootTrieNode {
+    children: HashMap<String, RootTrieNode>,
+    is_root: bool,
+}
+
+impl RootTrieNode {
+    fn insert(&mut self, root: &str) {
+        let mut node = self;
+        for component in root.split('/').filter(|c| !c.is_empty()) {
+            node = node.children.entry(component.to_string()).or_default();
+        }
+        node.is_root = true;
+    }
+
+    /// Finds the longest configured root that is a prefix of `path`'s components
+    fn longest_matching_root(&self, path: &str) -> Option<String> {
+        let mut node = self;
+        let mut matched: Option<Vec<&str>> = None;
+        let mut consumed = Vec::new();
+
+        for component in path.split('/') {
+            match node.children.get(component) {
+                Some(child) => {
+                    consumed.push(component);
+                    node = child;
+                    if node.is_root {
+                        matched = Some(consumed.clone());
+                    }
+                }
+                None => break,
+            }
+        }
+
+        matched.map(|segments| segments.join("/"))
+    }
+}
+
+/// Walks up from each changed file looking for a manifest file, collecting the
+/// distinct directories found as inferred project roots
+pub fn infer_project_roots(paths: &[String]) -> Vec<String> {
+    let mut roots = std::collections::HashSet::new();
+
+    for path in paths {
+        let mut dir = Path::new(path).parent();
+        while let Some(current) = dir {
+            if current.as_os_str().is_empty() {
+                break;
+            }
+            if MANIFEST_FILES.iter().any(|manifest| current.join(manifest).exists()) {
+                roots.insert(current.to_string_lossy().to_string());
+                break;
+            }
+            dir = current.parent();
+        }
+    }
+
+    roots.into_iter().collect()
+}
+
+/// Partitions changed file paths by which configured project root they fall under,
+/// using a trie lookup over the root path components. Paths outside any known root
+/// fall into the `(other)` catch-all group.
+pub fn partition_by_roots(
+    paths: &[String],
+    roots: &[String],
+) -> std::collections::BTreeMap<String, Vec<String>> {
+    let mut trie = RootTrieNode::default();
+    for root in roots {
+        trie.insert(root);
+    }
+
+    let mut groups: std::collections::BTreeMap<String, Vec<String>> =
+        std::collections::BTreeMap::new();
+
+    for path in paths {
+        let key = trie
+            .longest_matching_root(path)
+            .unwrap_or_else(|| "(other)".to_string());
+        groups.entry(key).or_default().push(path.clone());
+    }
+
+    groups
+}
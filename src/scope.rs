@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+/// A node in the path trie used to find the common directory staged files share
+#[derive(Default)]
+struct TrieNode {
+    children: HashMap<String, TrieNode>,
+}
+
+/// Suggests a Conventional Commits scope from a set of staged file paths by walking
+/// down a path trie while exactly one directory child is present at each level. The
+/// deepest such directory segment (e.g. `src/git`) becomes the suggested scope; if
+/// staged files diverge immediately at the root, no scope is suggested.
+pub fn suggest_scope(paths: &[String]) -> Option<String> {
+    if paths.is_empty() {
+        return None;
+    }
+
+    let mut root = TrieNode::default();
+    for path in paths {
+        let mut node = &mut root;
+        for component in path.split('/') {
+            node = node.children.entry(component.to_string()).or_default();
+        }
+    }
+
+    let mut scope_segments = Vec::new();
+    let mut node = &root;
+    loop {
+        if node.children.len() != 1 {
+            break;
+        }
+
+        let (segment, child) = node.children.iter().next().expect("len checked above");
+        // A childless node is a filename, not a directory - stop before descending into it
+        if child.children.is_empty() {
+            break;
+        }
+
+        scope_segments.push(segment.clone());
+        node = child;
+    }
+
+    if scope_segments.is_empty() {
+        None
+    } else {
+        Some(scope_segments.join("/"))
+    }
+}
@@ -10,31 +10,93 @@ use ratatui::{
 
 use crate::{App, AppState};
 
+/// Formats the repo status header, e.g. "on feature/x · ↑2 ↓0 · 1 stash"
+fn repo_status_text(app: &App) -> String {
+    let branch = app.repo_status.branch.as_deref().unwrap_or("detached HEAD");
+    let mut parts = vec![format!("on {branch}")];
+
+    parts.push(format!(
+        "↑{} ↓{}",
+        app.repo_status.ahead, app.repo_status.behind
+    ));
+
+    if app.repo_status.stash_count > 0 {
+        let noun = if app.repo_status.stash_count == 1 {
+            "stash"
+        } else {
+            "stashes"
+        };
+        parts.push(format!("{} {noun}", app.repo_status.stash_count));
+    }
+
+    parts.join(" · ")
+}
+
+/// Maps a porcelain status side to the color lsd/gitui-style file listers use for it
+fn status_char_color(status: &crate::git::StatusChar) -> Color {
+    use crate::git::StatusChar;
+    match status {
+        StatusChar::Added => Color::Green,
+        StatusChar::Modified => Color::Yellow,
+        StatusChar::Deleted => Color::Red,
+        StatusChar::Renamed => Color::Cyan,
+        StatusChar::Untracked => Color::DarkGray,
+        StatusChar::Conflicted => Color::Red,
+        StatusChar::Unchanged => Color::Reset,
+    }
+}
+
+/// Formats a unix timestamp as a short relative time, e.g. "3d ago"
+fn format_relative_time(epoch_secs: i64) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(epoch_secs);
+    let diff = (now - epoch_secs).max(0);
+
+    if diff < 60 {
+        format!("{diff}s ago")
+    } else if diff < 3600 {
+        format!("{}m ago", diff / 60)
+    } else if diff < 86400 {
+        format!("{}h ago", diff / 3600)
+    } else if diff < 2_592_000 {
+        format!("{}d ago", diff / 86400)
+    } else {
+        format!("{}mo ago", diff / 2_592_000)
+    }
+}
+
 /// Renders the TUI interface based on current application state
 pub fn render(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
-        .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+        .constraints([Constraint::Length(4), Constraint::Min(0)].as_ref())
         .split(f.area());
 
-    let title = if app.is_branch_mode {
-        Paragraph::new("Git Branch Creator (gitcc) 🌿")
-            .style(
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .block(Block::default().borders(Borders::ALL))
+    let title_line = if app.is_branch_mode {
+        ratatui::text::Line::from(ratatui::text::Span::styled(
+            "Git Branch Creator (gitcc) 🌿",
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ))
     } else {
-        Paragraph::new("Git Commit Creator (gitcc) 🚀")
-            .style(
-                Style::default()
-                    .fg(Color::Magenta)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .block(Block::default().borders(Borders::ALL))
+        ratatui::text::Line::from(ratatui::text::Span::styled(
+            "Git Commit Creator (gitcc) 🚀",
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        ))
     };
+    let status_line = ratatui::text::Line::from(ratatui::text::Span::styled(
+        repo_status_text(app),
+        Style::default().fg(Color::Cyan),
+    ));
+
+    let title = Paragraph::new(vec![title_line, status_line])
+        .block(Block::default().borders(Borders::ALL));
     f.render_widget(title, chunks[0]);
 
     match app.state {
@@ -44,18 +106,47 @@ pub fn render(f: &mut Frame, app: &mut App) {
                 .iter()
                 .enumerate()
                 .map(|(i, f)| {
+                    use crate::git::StatusChar;
+
                     let is_staged = app.staged_files_set.contains(f);
-                    let status_indicator =
-                        app.file_statuses.get(f).map(|s| s.as_str()).unwrap_or("?");
-                    let prefix = if is_staged { "[S]" } else { "[ ]" };
-                    let style = if i == app.selected_file_index {
-                        Style::default().bg(Color::Blue).fg(Color::White)
-                    } else if is_staged {
+                    let prefix = if is_staged { "[S] " } else { "[ ] " };
+                    let (index_char, worktree_char) = app
+                        .file_statuses
+                        .get(f)
+                        .map(|s| (s.index, s.worktree))
+                        .unwrap_or((StatusChar::Unchanged, StatusChar::Untracked));
+
+                    let conflicted = index_char == StatusChar::Conflicted
+                        || worktree_char == StatusChar::Conflicted;
+
+                    let mut index_style = Style::default().fg(status_char_color(&index_char));
+                    let mut worktree_style =
+                        Style::default().fg(status_char_color(&worktree_char));
+                    if conflicted {
+                        index_style = index_style.add_modifier(Modifier::BOLD);
+                        worktree_style = worktree_style.add_modifier(Modifier::BOLD);
+                    }
+
+                    let name_style = if is_staged {
                         Style::default().fg(Color::Green)
                     } else {
                         Style::default().fg(Color::Yellow)
                     };
-                    ListItem::new(format!("{prefix} [{status_indicator}] {f}")).style(style)
+
+                    let line = ratatui::text::Line::from(vec![
+                        ratatui::text::Span::raw(prefix),
+                        ratatui::text::Span::styled(index_char.as_str(), index_style),
+                        ratatui::text::Span::styled(worktree_char.as_str(), worktree_style),
+                        ratatui::text::Span::raw(" "),
+                        ratatui::text::Span::styled(f.as_str(), name_style),
+                    ]);
+
+                    let item = ListItem::new(line);
+                    if i == app.selected_file_index {
+                        item.style(Style::default().bg(Color::Blue))
+                    } else {
+                        item
+                    }
                 })
                 .collect();
 
@@ -63,23 +154,49 @@ pub fn render(f: &mut Frame, app: &mut App) {
                 .block(Block::default().title("📁 Files").borders(Borders::ALL))
                 .style(Style::default());
 
-            // Format diff with color coding
-            let all_diff_lines: Vec<ratatui::text::Line> = app
-                .current_diff
-                .lines()
-                .map(|line| {
-                    let style = if line.starts_with('+') && !line.starts_with("+++") {
-                        Style::default().fg(Color::Green)
-                    } else if line.starts_with('-') && !line.starts_with("---") {
-                        Style::default().fg(Color::Red)
-                    } else if line.starts_with("@@") {
-                        Style::default().fg(Color::Cyan)
-                    } else {
-                        Style::default()
-                    };
-                    ratatui::text::Line::from(ratatui::text::Span::styled(line, style))
-                })
-                .collect();
+            // Format diff with color coding; when the diff pane is focused and the file has
+            // hunks, render hunk-by-hunk instead so the hunk under the cursor can be highlighted
+            let diff_line_style = |line: &str| {
+                if line.starts_with('+') && !line.starts_with("+++") {
+                    Style::default().fg(Color::Green)
+                } else if line.starts_with('-') && !line.starts_with("---") {
+                    Style::default().fg(Color::Red)
+                } else if line.starts_with("@@") {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default()
+                }
+            };
+
+            let all_diff_lines: Vec<ratatui::text::Line> = match &app.file_diff {
+                Some(diff) if app.diff_focused && !diff.hunks.is_empty() => diff
+                    .hunks
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, hunk)| {
+                        let highlight = i == app.selected_hunk_index;
+                        std::iter::once(hunk.header.clone())
+                            .chain(hunk.lines.iter().cloned())
+                            .map(move |line| {
+                                let mut style = diff_line_style(&line);
+                                if highlight {
+                                    style = style.bg(Color::DarkGray);
+                                }
+                                ratatui::text::Line::from(ratatui::text::Span::styled(line, style))
+                            })
+                    })
+                    .collect(),
+                _ => app
+                    .current_diff
+                    .lines()
+                    .map(|line| {
+                        ratatui::text::Line::from(ratatui::text::Span::styled(
+                            line,
+                            diff_line_style(line),
+                        ))
+                    })
+                    .collect(),
+            };
 
             // Apply scroll offset
             let diff_lines: Vec<ratatui::text::Line> = all_diff_lines
@@ -99,11 +216,15 @@ pub fn render(f: &mut Frame, app: &mut App) {
             } else {
                 String::new()
             };
+            let loading_indicator = if app.diff_loading { " ⏳ computing…" } else { "" };
 
             let diff_widget = Paragraph::new(diff_lines)
                 .block(
                     Block::default()
-                        .title(format!("📝 Diff: {}{}", selected_file, scroll_indicator))
+                        .title(format!(
+                            "📝 Diff: {}{}{}",
+                            selected_file, scroll_indicator, loading_indicator
+                        ))
                         .borders(Borders::ALL),
                 )
                 .wrap(Wrap { trim: false });
@@ -114,7 +235,7 @@ pub fn render(f: &mut Frame, app: &mut App) {
                     .wrap(Wrap { trim: true })
             } else {
                 Paragraph::new(
-                    "↑↓ - scroll files, j/k - scroll diff, Space - stage, Enter - proceed, Esc - abort",
+                    "↑↓ - scroll files, j/k - scroll diff, Tab - focus diff (↑↓/j/k select hunk), Space - stage, h - stage hunks, b - blame, c - amend/fixup, Enter - proceed, Esc - abort",
                 )
                 .style(Style::default().fg(Color::Yellow))
                 .wrap(Wrap { trim: true })
@@ -223,6 +344,34 @@ pub fn render(f: &mut Frame, app: &mut App) {
             f.render_widget(filter_widget, layout[1]);
             f.render_widget(help, layout[2]);
         }
+        AppState::ScopeInput => {
+            let scope_with_cursor = if app.cursor_visible {
+                let mut chars: Vec<char> = app.scope_input.chars().collect();
+                chars.insert(app.cursor_position, '_');
+                chars.into_iter().collect()
+            } else {
+                app.scope_input.clone()
+            };
+            let input = Paragraph::new(scope_with_cursor)
+                .style(Style::default().fg(Color::Yellow))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Scope (optional, pre-filled from staged paths)"),
+                );
+
+            let help = Paragraph::new("Edit or clear the suggested scope, Enter to confirm, Esc to quit")
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true });
+
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Length(3)].as_ref())
+                .split(chunks[1]);
+
+            f.render_widget(input, layout[0]);
+            f.render_widget(help, layout[1]);
+        }
         AppState::MessageInput => {
             let message_with_cursor = if app.cursor_visible {
                 let mut chars: Vec<char> = app.commit_message.chars().collect();
@@ -354,5 +503,313 @@ pub fn render(f: &mut Frame, app: &mut App) {
             f.render_widget(input, layout[0]);
             f.render_widget(help, layout[1]);
         }
+        AppState::ProjectSelection => {
+            let items: Vec<ListItem> = app
+                .project_groups
+                .iter()
+                .enumerate()
+                .map(|(i, (name, files))| {
+                    let marker = if app.selected_projects.contains(&i) {
+                        "[x]"
+                    } else {
+                        "[ ]"
+                    };
+                    let style = if i == app.selected_project_index {
+                        Style::default().bg(Color::Blue).fg(Color::White)
+                    } else if app.selected_projects.contains(&i) {
+                        Style::default().fg(Color::Green)
+                    } else {
+                        Style::default()
+                    };
+                    ListItem::new(format!("{marker} {name} ({} files)", files.len())).style(style)
+                })
+                .collect();
+
+            let list = List::new(items).block(
+                Block::default()
+                    .title("📦 Affected Projects")
+                    .borders(Borders::ALL),
+            );
+
+            let help = Paragraph::new(
+                "↑↓ - navigate, Space - toggle, a - toggle all, Enter - commit selected, Esc - abort",
+            )
+            .style(Style::default().fg(Color::Yellow))
+            .wrap(Wrap { trim: true });
+
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+                .split(chunks[1]);
+
+            f.render_widget(list, layout[0]);
+            f.render_widget(help, layout[1]);
+        }
+        AppState::HunkReview => {
+            let selected_file = app
+                .all_files
+                .get(app.selected_file_index)
+                .map(|s| s.as_str())
+                .unwrap_or("");
+
+            let items: Vec<ListItem> = app
+                .file_diff
+                .as_ref()
+                .map(|diff| {
+                    diff.hunks
+                        .iter()
+                        .enumerate()
+                        .map(|(i, hunk)| {
+                            let style = if i == app.selected_hunk_index {
+                                Style::default().bg(Color::Blue).fg(Color::White)
+                            } else {
+                                Style::default().fg(Color::Yellow)
+                            };
+                            ListItem::new(hunk.header.clone()).style(style)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let action = if app.hunk_review_reverse {
+                "unstage"
+            } else {
+                "stage"
+            };
+            let hunks_list = List::new(items).block(
+                Block::default()
+                    .title(format!("🔍 Hunks to {action}: {selected_file}"))
+                    .borders(Borders::ALL),
+            );
+
+            let preview_lines: Vec<ratatui::text::Line> = app
+                .file_diff
+                .as_ref()
+                .and_then(|diff| diff.hunks.get(app.selected_hunk_index))
+                .map(|hunk| {
+                    std::iter::once(hunk.header.clone())
+                        .chain(hunk.lines.iter().cloned())
+                        .map(|line| {
+                            let style = if line.starts_with('+') {
+                                Style::default().fg(Color::Green)
+                            } else if line.starts_with('-') {
+                                Style::default().fg(Color::Red)
+                            } else if line.starts_with("@@") {
+                                Style::default().fg(Color::Cyan)
+                            } else {
+                                Style::default()
+                            };
+                            ratatui::text::Line::from(ratatui::text::Span::styled(line, style))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let preview = Paragraph::new(preview_lines)
+                .block(Block::default().title("Hunk preview").borders(Borders::ALL))
+                .wrap(Wrap { trim: false });
+
+            let help = Paragraph::new(format!(
+                "↑↓ - move between hunks, Enter/Space - {action} hunk, a - {action} all remaining, Esc - back"
+            ))
+            .style(Style::default().fg(Color::Yellow))
+            .wrap(Wrap { trim: true });
+
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(5), Constraint::Min(5), Constraint::Length(3)])
+                .split(chunks[1]);
+
+            f.render_widget(hunks_list, layout[0]);
+            f.render_widget(preview, layout[1]);
+            f.render_widget(help, layout[2]);
+        }
+        AppState::BranchList => {
+            let filtered = app.filtered_branches();
+            let items: Vec<ListItem> = filtered
+                .iter()
+                .enumerate()
+                .map(|(i, branch)| {
+                    let marker = if branch.is_head { "* " } else { "  " };
+                    let indicator = if branch.ahead > 0 || branch.behind > 0 {
+                        format!(" ↑{} ↓{}", branch.ahead, branch.behind)
+                    } else {
+                        String::new()
+                    };
+                    let style = if i == app.selected_branch_index {
+                        Style::default().bg(Color::DarkGray).fg(Color::White)
+                    } else if branch.is_head {
+                        Style::default().fg(Color::Green)
+                    } else {
+                        Style::default()
+                    };
+                    ListItem::new(format!("{marker}{}{indicator}", branch.name)).style(style)
+                })
+                .collect();
+
+            let list = List::new(items).block(
+                Block::default()
+                    .title("🌿 Branches")
+                    .borders(Borders::ALL),
+            );
+
+            let filter_display = if app.filter.is_empty() {
+                "Type to filter...".to_string()
+            } else {
+                format!("Filter: {}", app.filter)
+            };
+
+            let filter_widget = Paragraph::new(filter_display)
+                .style(Style::default().fg(Color::Cyan))
+                .block(Block::default().borders(Borders::ALL).title("Filter"));
+
+            let help = Paragraph::new(
+                "Type to filter, ↑↓ to navigate, Enter to checkout, Esc to quit",
+            )
+            .style(Style::default().fg(Color::Yellow))
+            .wrap(Wrap { trim: true });
+
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Min(0),
+                        Constraint::Length(3),
+                        Constraint::Length(3),
+                    ]
+                    .as_ref(),
+                )
+                .split(chunks[1]);
+
+            f.render_widget(list, layout[0]);
+            f.render_widget(filter_widget, layout[1]);
+            f.render_widget(help, layout[2]);
+        }
+        AppState::CommitHistory => {
+            let items: Vec<ListItem> = app
+                .commits
+                .iter()
+                .enumerate()
+                .map(|(i, commit)| {
+                    let style = if i == app.selected_commit_index {
+                        Style::default().bg(Color::DarkGray).fg(Color::White)
+                    } else if i == 0 {
+                        Style::default().fg(Color::Green)
+                    } else {
+                        Style::default()
+                    };
+                    ListItem::new(format!(
+                        "{} {} · {}",
+                        commit.short_id, commit.subject, commit.author
+                    ))
+                    .style(style)
+                })
+                .collect();
+
+            let list = List::new(items).block(
+                Block::default()
+                    .title("🕘 Recent Commits")
+                    .borders(Borders::ALL),
+            );
+
+            let (help_text, help_color) = if app.confirm_pushed_action {
+                (
+                    "⚠️ HEAD is already pushed - press a/r again to rewrite it anyway, Esc to cancel",
+                    Color::Red,
+                )
+            } else {
+                (
+                    "↑↓ - navigate, a - amend HEAD, r - reword HEAD, f - fixup! selected, Esc - back",
+                    Color::Yellow,
+                )
+            };
+            let help = Paragraph::new(help_text)
+                .style(Style::default().fg(help_color))
+                .wrap(Wrap { trim: true });
+
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+                .split(chunks[1]);
+
+            f.render_widget(list, layout[0]);
+            f.render_widget(help, layout[1]);
+        }
+        AppState::BlameView => {
+            let selected_file = app
+                .all_files
+                .get(app.selected_file_index)
+                .map(|s| s.as_str())
+                .unwrap_or("");
+
+            let all_lines: Vec<ratatui::text::Line> = app
+                .file_blame
+                .as_ref()
+                .map(|blame| {
+                    blame
+                        .lines
+                        .iter()
+                        .map(|(hunk, line)| {
+                            let gutter = match hunk {
+                                Some(h) => format!(
+                                    "{:.7} {:<15} {:>9} │ ",
+                                    h.commit_id.to_string(),
+                                    h.author,
+                                    format_relative_time(h.time)
+                                ),
+                                None => format!("{:7} {:<15} {:>9} │ ", "", "", ""),
+                            };
+                            ratatui::text::Line::from(vec![
+                                ratatui::text::Span::styled(
+                                    gutter,
+                                    Style::default().fg(Color::Cyan),
+                                ),
+                                ratatui::text::Span::raw(line.clone()),
+                            ])
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let total_lines = all_lines.len();
+            let blame_lines: Vec<ratatui::text::Line> =
+                all_lines.into_iter().skip(app.diff_scroll_offset).collect();
+
+            let blame_widget = Paragraph::new(blame_lines)
+                .block(
+                    Block::default()
+                        .title(format!("🕵️ Blame: {selected_file}"))
+                        .borders(Borders::ALL),
+                )
+                .wrap(Wrap { trim: false });
+
+            let help = Paragraph::new("↑↓/j/k - scroll, Esc - back")
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true });
+
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(5), Constraint::Length(3)])
+                .split(chunks[1]);
+
+            app.diff_visible_lines = layout[0].height.saturating_sub(2) as usize;
+            f.render_widget(blame_widget, layout[0]);
+
+            if total_lines > app.diff_visible_lines {
+                let max_scroll = total_lines.saturating_sub(app.diff_visible_lines);
+                let mut scrollbar_state = ScrollbarState::default()
+                    .content_length(max_scroll.saturating_add(1))
+                    .position(app.diff_scroll_offset);
+
+                let scrollbar = Scrollbar::default()
+                    .orientation(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(Some("↑"))
+                    .end_symbol(Some("↓"));
+
+                f.render_stateful_widget(scrollbar, layout[0], &mut scrollbar_state);
+            }
+
+            f.render_widget(help, layout[1]);
+        }
     }
 }